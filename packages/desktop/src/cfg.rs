@@ -0,0 +1,269 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+use tao::accelerator::{Accelerator, SysMods};
+use tao::dpi::{PhysicalPosition, PhysicalSize};
+use tao::keyboard::KeyCode;
+use tao::menu::{MenuBar, MenuItem};
+use wry::application::window::{WindowBuilder, WindowId};
+use wry::http::{Request, Response};
+
+/// A global application hotkey registered with [`DesktopConfig::with_shortcut`]: the key
+/// combination to match, and the event name dispatched into the app's scheduler when it fires.
+#[derive(Debug, Clone)]
+pub(crate) struct Shortcut {
+    pub accelerator: Accelerator,
+    pub name: String,
+}
+
+/// A custom protocol handler, as registered with [`DesktopConfig::with_custom_protocol`].
+///
+/// It receives the full request made against the registered scheme and must produce a
+/// complete response (bytes + mimetype).
+pub type ProtocolHandler = Arc<dyn Fn(&Request) -> wry::Result<Response> + Send + Sync + 'static>;
+
+/// A window or OS-level lifecycle event, forwarded to the handler registered with
+/// [`DesktopConfig::with_window_event_handler`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum DesktopEvent {
+    /// The window was resized.
+    Resized(PhysicalSize<u32>),
+    /// The window was moved.
+    Moved(PhysicalPosition<i32>),
+    /// The window gained (`true`) or lost (`false`) focus.
+    Focused(bool),
+    /// A file was dropped onto the window.
+    FileDropped(PathBuf),
+    /// The user (or OS) asked to close the window.
+    ///
+    /// Return `false` from the handler to veto the close and keep the window open; the return
+    /// value is ignored for every other variant.
+    CloseRequested,
+}
+
+/// A handler for [`DesktopEvent`]s, registered with [`DesktopConfig::with_window_event_handler`].
+///
+/// The `WindowId` identifies which window the event belongs to — every window spawned from this
+/// `DesktopConfig` (and, if the same handler is reused across multiple `DesktopConfig`s, every
+/// window spawned from any of them) is delivered through the same handler, so this is how it
+/// tells them apart.
+pub type WindowEventHandler = Arc<dyn Fn(WindowId, &DesktopEvent) -> bool + Send + Sync + 'static>;
+
+/// The configuration for the WebView application.
+///
+/// This lets you configure the initial window, custom assets, and other desktop-specific
+/// behaviors of your app before it launches.
+pub struct DesktopConfig<'a> {
+    pub window: WindowBuilder,
+    pub(crate) menu: Option<MenuBar>,
+    pub(crate) asset_dirs: Vec<PathBuf>,
+    pub(crate) protocols: Vec<(String, ProtocolHandler)>,
+    pub(crate) window_event_handler: Option<WindowEventHandler>,
+    pub(crate) shortcuts: Vec<Shortcut>,
+    pub(crate) quit_shortcut: Option<Accelerator>,
+    _marker: std::marker::PhantomData<&'a ()>,
+}
+
+impl<'a> DesktopConfig<'a> {
+    /// Initializes a new `DesktopConfig` with a default window, no custom menu, and the default
+    /// `Cmd+Q` / `Ctrl+Q` quit shortcut.
+    pub fn new() -> Self {
+        Self {
+            window: WindowBuilder::new(),
+            menu: None,
+            asset_dirs: Vec::new(),
+            protocols: Vec::new(),
+            window_event_handler: None,
+            shortcuts: Vec::new(),
+            quit_shortcut: Some(Accelerator::new(SysMods::Cmd, KeyCode::KeyQ)),
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Configure the WebView's window using a closure that takes the default `WindowBuilder`
+    /// and returns a customized one.
+    pub fn with_window(
+        &mut self,
+        configure: impl FnOnce(WindowBuilder) -> WindowBuilder,
+    ) -> &mut Self {
+        self.window = configure(self.window.clone());
+        self
+    }
+
+    /// Configure the application's native menu bar using a closure that takes an empty `MenuBar`
+    /// and returns the one you'd like to use.
+    ///
+    /// Custom (non-native) menu items created with this closure will have their click events
+    /// delivered back into your app through the same channel that drives DOM events, so you can
+    /// react to them like any other user interaction.
+    ///
+    /// ```rust, ignore
+    /// dioxus_desktop::launch_cfg(app, |c| {
+    ///     c.with_menu(|menu| {
+    ///         // ... add submenus and items to `menu`
+    ///         menu
+    ///     })
+    /// });
+    /// ```
+    pub fn with_menu(&mut self, configure: impl FnOnce(MenuBar) -> MenuBar) -> &mut Self {
+        self.menu = Some(configure(MenuBar::new()));
+        self
+    }
+
+    /// Register a directory of static assets to be served under `wry://assets/`.
+    ///
+    /// Files that live under a registered asset directory can be referenced from your app with
+    /// e.g. `wry://assets/logo.png`, and are served from disk with their mimetype detected from
+    /// the file extension. Directories are searched in the order they were registered.
+    pub fn with_asset_dir(&mut self, dir: PathBuf) -> &mut Self {
+        self.asset_dirs.push(dir);
+        self
+    }
+
+    /// Register a handler for a custom protocol scheme (e.g. `myapp://`).
+    ///
+    /// The handler is responsible for producing a complete response (bytes + mimetype) for any
+    /// request made against `<scheme>://...`. Unlike [`DesktopConfig::with_asset_dir`], this
+    /// gives you full control, including serving generated or embedded content.
+    pub fn with_custom_protocol<F>(&mut self, scheme: impl Into<String>, handler: F) -> &mut Self
+    where
+        F: Fn(&Request) -> wry::Result<Response> + Send + Sync + 'static,
+    {
+        self.protocols.push((scheme.into(), Arc::new(handler)));
+        self
+    }
+
+    /// Register a handler that's called for window and OS-level lifecycle events (resize, move,
+    /// focus changes, dropped files, close requests) that otherwise never reach your app.
+    ///
+    /// This applies only to the window(s) spawned from this config — a window opened at runtime
+    /// via `DesktopContext::new_window` with its own `DesktopConfig` needs its own call to this
+    /// method if it wants a handler.
+    ///
+    /// Return `false` from the handler when handling [`DesktopEvent::CloseRequested`] to veto
+    /// the close and keep the window open; the return value is ignored for every other event.
+    pub fn with_window_event_handler(
+        &mut self,
+        handler: impl Fn(WindowId, &DesktopEvent) -> bool + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.window_event_handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// Register an application hotkey. When `accelerator` is pressed while one of the app's
+    /// windows has focus, an event named `name` is dispatched into every open window's
+    /// scheduler, the same way custom menu item clicks are.
+    ///
+    /// Note this is matched against keyboard input for whichever window currently has focus,
+    /// not registered as an OS-level global hotkey (via e.g. tao's `GlobalShortcutManager`) that
+    /// would fire while the app is in the background — if you need that, register it directly
+    /// with tao/wry instead.
+    ///
+    /// Registering more than one shortcut under the same `name` is fine; unregister them all at
+    /// once later with [`DesktopController::unregister_shortcut`](crate::DesktopController::unregister_shortcut).
+    ///
+    /// Shortcuts registered here become active when the window spawned from this config opens.
+    /// From then on they're matched against keyboard input in any of the app's windows, and they
+    /// stay registered after that window closes until they're unregistered.
+    pub fn with_shortcut(&mut self, accelerator: Accelerator, name: impl Into<String>) -> &mut Self {
+        self.shortcuts.push(Shortcut {
+            accelerator,
+            name: name.into(),
+        });
+        self
+    }
+
+    /// Override the default `Cmd+Q` / `Ctrl+Q` quit shortcut, or disable it entirely with
+    /// `None` so the app can only be closed by other means (e.g. closing its windows).
+    ///
+    /// This is read per-window: it's matched only while the window spawned from this config has
+    /// focus, so a window opened at runtime via `DesktopContext::new_window` needs its own call
+    /// to this method if it wants a (possibly different) quit shortcut. Whichever window's quit
+    /// shortcut matches, the whole app exits.
+    pub fn with_quit_shortcut(&mut self, accelerator: impl Into<Option<Accelerator>>) -> &mut Self {
+        self.quit_shortcut = accelerator.into();
+        self
+    }
+
+    /// The menu bar Dioxus Desktop ships with out of the box, used whenever the app doesn't
+    /// provide its own via [`DesktopConfig::with_menu`].
+    pub(crate) fn default_menu() -> MenuBar {
+        let mut menu_bar_menu = MenuBar::new();
+
+        // create `first_menu`
+        let mut first_menu = MenuBar::new();
+
+        first_menu.add_native_item(MenuItem::About("App".to_string()));
+        first_menu.add_native_item(MenuItem::Services);
+        first_menu.add_native_item(MenuItem::Separator);
+        first_menu.add_native_item(MenuItem::Hide);
+        first_menu.add_native_item(MenuItem::HideOthers);
+        first_menu.add_native_item(MenuItem::ShowAll);
+
+        first_menu.add_native_item(MenuItem::Quit);
+        first_menu.add_native_item(MenuItem::CloseWindow);
+
+        // create second menu
+        let mut second_menu = MenuBar::new();
+
+        second_menu.add_native_item(MenuItem::Copy);
+        second_menu.add_native_item(MenuItem::Paste);
+        second_menu.add_native_item(MenuItem::SelectAll);
+
+        menu_bar_menu.add_submenu("First menu", true, first_menu);
+        menu_bar_menu.add_submenu("Second menu", true, second_menu);
+
+        menu_bar_menu
+    }
+}
+
+impl<'a> Default for DesktopConfig<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Resolve `asset_path` against `dir`, rejecting anything that would escape `dir` (e.g. via
+/// `..` components or a symlink) before the caller reads it off disk.
+///
+/// Returns `None` if the request doesn't resolve to a real file inside `dir`.
+pub(crate) fn resolve_asset(dir: &std::path::Path, asset_path: &str) -> Option<PathBuf> {
+    let dir = dir.canonicalize().ok()?;
+    let candidate = dir.join(asset_path).canonicalize().ok()?;
+    (candidate.starts_with(&dir) && candidate.is_file()).then_some(candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_asset_rejects_paths_that_escape_the_dir() {
+        let dir = std::env::temp_dir().join("dioxus-desktop-resolve-asset-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("inside.txt"), b"ok").unwrap();
+
+        assert!(resolve_asset(&dir, "inside.txt").is_some());
+        assert!(resolve_asset(&dir, "../../../../etc/passwd").is_none());
+        assert!(resolve_asset(&dir, "does-not-exist.txt").is_none());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
+
+/// Best-effort mimetype detection from a file's extension, for assets served over `wry://`.
+pub(crate) fn mime_from_path(path: &std::path::Path) -> &'static str {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("html") => "text/html",
+        Some("js") => "text/javascript",
+        Some("css") => "text/css",
+        Some("json") => "application/json",
+        Some("png") => "image/png",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("svg") => "image/svg+xml",
+        Some("ico") => "image/x-icon",
+        Some("wasm") => "application/wasm",
+        Some("woff") => "font/woff",
+        Some("woff2") => "font/woff2",
+        _ => "application/octet-stream",
+    }
+}
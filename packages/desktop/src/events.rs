@@ -0,0 +1,58 @@
+//! Translate events coming in from the WebView (over the `user_event` RPC call) or from native
+//! OS widgets (like the menu bar) into the `UserEvent`s that `SchedulerMsg::Event` expects.
+
+use dioxus_core::{ElementId, EventPriority, UserEvent};
+use serde::Deserialize;
+use std::sync::Arc;
+
+/// The shape of the JSON payload the interpreter sends over the `user_event` RPC call.
+#[derive(Debug, Deserialize)]
+struct ImEvent {
+    event: String,
+    mounted_dom_id: u64,
+    params: serde_json::Value,
+}
+
+/// Decode a serialized DOM event (as delivered by the interpreter's `user_event` RPC call) into
+/// the `UserEvent` the virtualdom's scheduler expects.
+pub fn trigger_from_serialized(val: serde_json::Value) -> UserEvent {
+    let ImEvent {
+        event,
+        mounted_dom_id,
+        params,
+    } = serde_json::from_value(val).unwrap();
+
+    UserEvent {
+        scope_id: None,
+        priority: EventPriority::Medium,
+        name: Box::leak(event.into_boxed_str()),
+        element: Some(ElementId(mounted_dom_id as usize)),
+        data: Arc::new(params),
+    }
+}
+
+/// Build a synthetic `UserEvent` for a native menu item click, keyed by its numeric `MenuId`.
+///
+/// This reuses the same `UserEvent` shape as DOM events so menu clicks flow through the
+/// scheduler exactly like any other user interaction.
+pub fn trigger_from_menu_id(menu_id: u32) -> UserEvent {
+    UserEvent {
+        scope_id: None,
+        priority: EventPriority::Medium,
+        name: "menu",
+        element: None,
+        data: Arc::new(menu_id),
+    }
+}
+
+/// Build a synthetic `UserEvent` for a matched application shortcut, keyed by the name it was
+/// registered under via `DesktopConfig::with_shortcut`.
+pub fn trigger_from_shortcut(name: String) -> UserEvent {
+    UserEvent {
+        scope_id: None,
+        priority: EventPriority::Medium,
+        name: "shortcut",
+        element: None,
+        data: Arc::new(name),
+    }
+}
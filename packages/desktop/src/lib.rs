@@ -6,7 +6,7 @@
 //!
 //! One of Dioxus' killer features is the ability to quickly build a native desktop app that looks and feels the same across platforms. Apps built with Dioxus are typically <5mb in size and use existing system resources, so they won't hog extreme amounts of RAM or memory.
 //!
-//! Dioxus Desktop is built off Tauri. Right now there aren't any Dioxus abstractions over keyboard shortcuts, menubar, handling, etc, so you'll want to leverage Tauri - mostly [Wry](http://github.com/tauri-apps/wry/) and [Tao](http://github.com/tauri-apps/tao)) directly. The next major release of Dioxus-Desktop will include components and hooks for notifications, global shortcuts, menubar, etc.
+//! Dioxus Desktop is built off Tauri. `DesktopConfig` now exposes Dioxus-native abstractions over the menubar, application shortcuts, and custom assets, but for anything not yet covered you can still reach for [Wry](http://github.com/tauri-apps/wry/) and [Tao](http://github.com/tauri-apps/tao)) directly.
 //!
 //!
 //! ## Getting Set up
@@ -62,11 +62,10 @@ use std::{
     sync::{Arc, RwLock},
 };
 use tao::{
-    accelerator::{Accelerator, SysMods},
+    accelerator::Accelerator,
     event::{Event, StartCause, WindowEvent},
     event_loop::{ControlFlow, EventLoop, EventLoopWindowTarget},
-    keyboard::{KeyCode, ModifiersState},
-    menu::{MenuBar, MenuItem},
+    keyboard::ModifiersState,
     window::{Window, WindowId},
 };
 pub use wry;
@@ -156,40 +155,108 @@ pub fn launch_with_props<P: 'static + Send>(
     builder(&mut desktop_cfg);
 
     let event_loop = EventLoop::with_user_event();
-    let mut desktop = DesktopController::new_on_tokio(root, props, event_loop.create_proxy());
-    let quit_hotkey = Accelerator::new(SysMods::Cmd, KeyCode::KeyQ);
-    let modifiers = ModifiersState::default();
+    let proxy = event_loop.create_proxy();
+    let mut desktop = DesktopController::new();
+    let mut first_window = Some((root, props));
+    let mut modifiers = ModifiersState::default();
 
     event_loop.run(move |window_event, event_loop, control_flow| {
         *control_flow = ControlFlow::Wait;
 
         match window_event {
-            Event::NewEvents(StartCause::Init) => desktop.new_window(&desktop_cfg, event_loop),
+            Event::NewEvents(StartCause::Init) => {
+                let (root, props) = first_window.take().expect("app already launched");
+                desktop.spawn_window(root, props, &desktop_cfg, event_loop, proxy.clone());
+            }
 
             Event::WindowEvent {
                 event, window_id, ..
             } => {
                 match event {
-                    WindowEvent::CloseRequested => *control_flow = ControlFlow::Exit,
+                    WindowEvent::CloseRequested => {
+                        let allow_close = desktop
+                            .window_event_handler(window_id)
+                            .map(|handler| handler(window_id, &cfg::DesktopEvent::CloseRequested))
+                            .unwrap_or(true);
+
+                        if allow_close {
+                            desktop.close_window(window_id, control_flow);
+                        }
+                    }
                     WindowEvent::Destroyed { .. } => desktop.close_window(window_id, control_flow),
 
+                    WindowEvent::ModifiersChanged(state) => modifiers = state,
+
                     WindowEvent::KeyboardInput { event, .. } => {
-                        if quit_hotkey.matches(&modifiers, &event.physical_key) {
-                            desktop.close_window(window_id, control_flow);
+                        // Key releases are reported too; only a press triggers a shortcut.
+                        if event.state != tao::event::ElementState::Pressed {
+                            return;
+                        }
+
+                        let quit_hotkey = desktop
+                            .windows
+                            .get(&window_id)
+                            .and_then(|window| window.quit_shortcut.clone());
+                        if let Some(quit_hotkey) = quit_hotkey {
+                            if quit_hotkey.matches(&modifiers, &event.physical_key) {
+                                desktop.exit_app(control_flow);
+                            }
+                        }
+
+                        for (accelerator, name) in &desktop.shortcuts {
+                            if accelerator.matches(&modifiers, &event.physical_key) {
+                                let name = name.clone();
+                                desktop
+                                    .send_to_all_windows(|| events::trigger_from_shortcut(name.clone()));
+                            }
+                        }
+                    }
+
+                    WindowEvent::Resized(size) => {
+                        if let Some(view) = desktop.webviews.get_mut(&window_id) {
+                            let _ = view.resize();
+                        }
+                        if let Some(handler) = desktop.window_event_handler(window_id) {
+                            handler(window_id, &cfg::DesktopEvent::Resized(size));
                         }
                     }
 
-                    WindowEvent::Resized(_) | WindowEvent::Moved(_) => {
+                    WindowEvent::Moved(position) => {
                         if let Some(view) = desktop.webviews.get_mut(&window_id) {
                             let _ = view.resize();
                         }
+                        if let Some(handler) = desktop.window_event_handler(window_id) {
+                            handler(window_id, &cfg::DesktopEvent::Moved(position));
+                        }
+                    }
+
+                    WindowEvent::Focused(is_focused) => {
+                        if let Some(handler) = desktop.window_event_handler(window_id) {
+                            handler(window_id, &cfg::DesktopEvent::Focused(is_focused));
+                        }
+                    }
+
+                    WindowEvent::DroppedFile(path) => {
+                        if let Some(handler) = desktop.window_event_handler(window_id) {
+                            handler(window_id, &cfg::DesktopEvent::FileDropped(path));
+                        }
                     }
 
-                    // TODO: we want to shuttle all of these events into the user's app or provide some handler
                     _ => {}
                 }
             }
 
+            // Native (non-DOM) menu items, like the custom ones an app registers via
+            // `DesktopConfig::with_menu`, are delivered into every open window's scheduler the
+            // same way DOM events are so the app can react to them like any other user
+            // interaction.
+            Event::MenuEvent { menu_id, .. } => {
+                desktop.send_to_all_windows(|| events::trigger_from_menu_id(menu_id.0));
+            }
+
+            Event::UserEvent(UserWindowEvent::NewWindow(open)) => {
+                open(&mut desktop, event_loop, proxy.clone());
+            }
             Event::UserEvent(_evt) => {
                 desktop.try_load_ready_webviews();
             }
@@ -208,114 +275,254 @@ pub fn launch_with_props<P: 'static + Send>(
 pub enum UserWindowEvent {
     Start,
     Update,
+    /// Open an additional native window at runtime, e.g. from inside a running component via
+    /// [`DesktopContext::new_window`]. The closure is responsible for spawning the new window's
+    /// own `VirtualDom` and registering it with the controller.
+    NewWindow(
+        Box<
+            dyn FnOnce(
+                    &mut DesktopController,
+                    &EventLoopWindowTarget<UserWindowEvent>,
+                    EventLoopProxy<UserWindowEvent>,
+                ) + Send,
+        >,
+    ),
 }
 
-pub struct DesktopController {
-    pub webviews: HashMap<WindowId, WebView>,
-    pub sender: futures_channel::mpsc::UnboundedSender<SchedulerMsg>,
-    pub pending_edits: Arc<RwLock<VecDeque<String>>>,
-    pub quit_app_on_close: bool,
-    pub is_ready: Arc<AtomicBool>,
+/// A pending `eval` call, keyed by id, waiting on its result to come back from the webview.
+type EvalResults = Arc<std::sync::Mutex<HashMap<u64, futures_channel::oneshot::Sender<serde_json::Value>>>>;
+
+/// A future resolving to the value an `eval`'d JS snippet returned.
+pub type EvalFuture = std::pin::Pin<Box<dyn std::future::Future<Output = serde_json::Value>>>;
+
+/// The payload the interpreter sends back over the `eval_result` RPC call once an `eval`'d
+/// snippet has finished running.
+#[derive(serde::Deserialize)]
+struct EvalResult {
+    id: u64,
+    result: serde_json::Value,
 }
 
-impl DesktopController {
-    // Launch the virtualdom on its own thread managed by tokio
-    // returns the desktop state
-    pub fn new_on_tokio<P: Send + 'static>(
+/// A handle each window provides to its root scope (via [`ScopeState::provide_context`]), which
+/// components read with `cx.consume_context` to interact with the desktop shell: opening
+/// additional native windows at runtime, and `eval`-ing JS in this component's webview.
+#[derive(Clone)]
+pub struct DesktopContext {
+    proxy: EventLoopProxy<UserWindowEvent>,
+    eval_queue: Arc<RwLock<VecDeque<(u64, String)>>>,
+    eval_results: EvalResults,
+    eval_counter: Arc<std::sync::atomic::AtomicU64>,
+}
+
+impl DesktopContext {
+    fn new(proxy: EventLoopProxy<UserWindowEvent>) -> Self {
+        Self {
+            proxy,
+            eval_queue: Arc::new(RwLock::new(VecDeque::new())),
+            eval_results: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            eval_counter: Arc::new(std::sync::atomic::AtomicU64::new(0)),
+        }
+    }
+
+    /// Evaluate a snippet of JavaScript in this window's webview and asynchronously resolve to
+    /// whatever it returns (or `Value::Null` if it throws, or if it couldn't be run at all).
+    ///
+    /// Unlike the one-way `evaluate_script`, this gives you a proper request/response bridge:
+    /// the snippet is run, its return value is sent back over the same RPC channel DOM events
+    /// use, and the future you get back resolves once that response arrives.
+    pub fn eval(&self, js: impl Into<String>) -> EvalFuture {
+        let id = self
+            .eval_counter
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let (sender, receiver) = futures_channel::oneshot::channel();
+
+        self.eval_results.lock().unwrap().insert(id, sender);
+        self.eval_queue.write().unwrap().push_back((id, js.into()));
+
+        Box::pin(async move { receiver.await.unwrap_or(serde_json::Value::Null) })
+    }
+
+    /// Open an additional native window running its own `VirtualDom` for `root`/`props`.
+    ///
+    /// The window is spawned asynchronously: this returns immediately and the window appears
+    /// once the event loop processes the request.
+    pub fn new_window<P: Send + 'static>(
+        &self,
         root: Component<P>,
         props: P,
-        evt: EventLoopProxy<UserWindowEvent>,
-    ) -> Self {
-        let edit_queue = Arc::new(RwLock::new(VecDeque::new()));
-        let pending_edits = edit_queue.clone();
+        cfg: DesktopConfig<'static>,
+    ) {
+        let _ = self
+            .proxy
+            .send_event(UserWindowEvent::NewWindow(Box::new(
+                move |controller, event_loop, evt| {
+                    controller.spawn_window(root, props, &cfg, event_loop, evt);
+                },
+            )));
+    }
+}
 
-        let (sender, receiver) = futures_channel::mpsc::unbounded::<SchedulerMsg>();
-        let return_sender = sender.clone();
-
-        std::thread::spawn(move || {
-            // We create the runtim as multithreaded, so you can still "spawn" onto multiple threads
-            let runtime = tokio::runtime::Builder::new_multi_thread()
-                .enable_all()
-                .build()
-                .unwrap();
-
-            runtime.block_on(async move {
-                let mut dom =
-                    VirtualDom::new_with_props_and_scheduler(root, props, (sender, receiver));
-
-                let edits = dom.rebuild();
-
-                edit_queue
-                    .write()
-                    .unwrap()
-                    .push_front(serde_json::to_string(&edits.edits).unwrap());
-
-                loop {
-                    dom.wait_for_work().await;
-                    let mut muts = dom.work_with_deadline(|| false);
-                    while let Some(edit) = muts.pop() {
-                        edit_queue
-                            .write()
-                            .unwrap()
-                            .push_front(serde_json::to_string(&edit.edits).unwrap());
-                    }
-                    let _ = evt.send_event(UserWindowEvent::Update);
-                }
-            })
-        });
+/// The per-window state backing a single open window: its own `VirtualDom` scheduler channel,
+/// queue of pending edits, and readiness flag. Keeping these separate per window (rather than
+/// one shared set for the whole app) is what lets multiple windows run independent UIs without
+/// edits or RPC events from one window leaking into another.
+struct WindowContext {
+    sender: futures_channel::mpsc::UnboundedSender<SchedulerMsg>,
+    pending_edits: Arc<RwLock<VecDeque<String>>>,
+    is_ready: Arc<AtomicBool>,
+    eval_queue: Arc<RwLock<VecDeque<(u64, String)>>>,
+    /// The pending `eval` calls waiting on a result from this window's webview, so a failed
+    /// injection can resolve them to `Value::Null` instead of leaving them waiting forever.
+    eval_results: EvalResults,
+    /// Tells this window's `VirtualDom` thread to stop, so closing a window (without closing the
+    /// whole app) doesn't leak its thread and `VirtualDom` forever.
+    shutdown: futures_channel::oneshot::Sender<()>,
+    thread: std::thread::JoinHandle<()>,
+    /// This window's own lifecycle-event handler and quit shortcut, read from the `DesktopConfig`
+    /// it was spawned with (rather than whichever config `launch_with_props` was originally
+    /// called with), so windows opened at runtime via `DesktopContext::new_window` honor their
+    /// own config instead of silently falling back to the first window's.
+    window_event_handler: Option<cfg::WindowEventHandler>,
+    quit_shortcut: Option<Accelerator>,
+}
 
-        Self {
-            pending_edits,
-            sender: return_sender,
+pub struct DesktopController {
+    pub webviews: HashMap<WindowId, WebView>,
+    windows: HashMap<WindowId, WindowContext>,
+    pub quit_app_on_close: bool,
+    /// Registered application hotkeys, matched against every `WindowEvent::KeyboardInput` for
+    /// whichever window has focus (not true OS-level global hotkeys).
+    shortcuts: Vec<(Accelerator, String)>,
+}
 
+impl DesktopController {
+    pub fn new() -> Self {
+        Self {
             webviews: HashMap::new(),
-            is_ready: Arc::new(AtomicBool::new(false)),
+            windows: HashMap::new(),
             quit_app_on_close: true,
+            shortcuts: Vec::new(),
+        }
+    }
+
+    /// Register an application hotkey at runtime. When `accelerator` is pressed while one of the
+    /// app's windows has focus, an event named `name` is dispatched into every open window's
+    /// scheduler.
+    pub fn register_shortcut(&mut self, accelerator: Accelerator, name: impl Into<String>) {
+        self.shortcuts.push((accelerator, name.into()));
+    }
+
+    /// Unregister every shortcut previously registered under `name`.
+    pub fn unregister_shortcut(&mut self, name: &str) {
+        self.shortcuts.retain(|(_, registered)| registered != name);
+    }
+
+    /// The lifecycle-event handler `window_id`'s window was spawned with, if any.
+    fn window_event_handler(&self, window_id: WindowId) -> Option<cfg::WindowEventHandler> {
+        self.windows
+            .get(&window_id)
+            .and_then(|window| window.window_event_handler.clone())
+    }
+
+    /// Send an event, built fresh for each open window, into every window's scheduler.
+    fn send_to_all_windows(&self, mut make_event: impl FnMut() -> UserEvent) {
+        for window in self.windows.values() {
+            let _ = window
+                .sender
+                .unbounded_send(SchedulerMsg::Event(make_event()));
         }
     }
 
-    pub fn new_window(
+    /// Launch `root`'s `VirtualDom` on its own tokio-backed thread and open a native window for
+    /// it, wiring its edits, readiness, and RPC events to this window alone.
+    pub fn spawn_window<P: Send + 'static>(
         &mut self,
+        root: Component<P>,
+        props: P,
         cfg: &DesktopConfig,
         event_loop: &EventLoopWindowTarget<UserWindowEvent>,
+        evt: EventLoopProxy<UserWindowEvent>,
     ) {
-        let builder = cfg.window.clone().with_menu({
-            // create main menubar menu
-            let mut menu_bar_menu = MenuBar::new();
-
-            // create `first_menu`
-            let mut first_menu = MenuBar::new();
+        let menu = cfg.menu.clone().unwrap_or_else(DesktopConfig::default_menu);
+        let builder = cfg.window.clone().with_menu(menu);
 
-            first_menu.add_native_item(MenuItem::About("App".to_string()));
-            first_menu.add_native_item(MenuItem::Services);
-            first_menu.add_native_item(MenuItem::Separator);
-            first_menu.add_native_item(MenuItem::Hide);
-            first_menu.add_native_item(MenuItem::HideOthers);
-            first_menu.add_native_item(MenuItem::ShowAll);
-
-            first_menu.add_native_item(MenuItem::Quit);
-            first_menu.add_native_item(MenuItem::CloseWindow);
+        for shortcut in cfg.shortcuts.iter().cloned() {
+            self.register_shortcut(shortcut.accelerator, shortcut.name);
+        }
 
-            // create second menu
-            let mut second_menu = MenuBar::new();
+        let window = builder.build(event_loop).unwrap();
+        let window_id = window.id();
 
-            // second_menu.add_submenu("Sub menu", true, my_sub_menu);
-            second_menu.add_native_item(MenuItem::Copy);
-            second_menu.add_native_item(MenuItem::Paste);
-            second_menu.add_native_item(MenuItem::SelectAll);
+        let edit_queue = Arc::new(RwLock::new(VecDeque::new()));
+        let is_ready = Arc::new(AtomicBool::new(false));
+        let (sender, receiver) = futures_channel::mpsc::unbounded::<SchedulerMsg>();
+        let view_sender = sender.clone();
+        let (shutdown_tx, mut shutdown_rx) = futures_channel::oneshot::channel::<()>();
+
+        let desktop_context = DesktopContext::new(evt.clone());
+        let eval_queue = desktop_context.eval_queue.clone();
+        let eval_results = desktop_context.eval_results.clone();
+
+        let thread = {
+            let edit_queue = edit_queue.clone();
+            let desktop_context = desktop_context.clone();
+            std::thread::spawn(move || {
+                // We create the runtim as multithreaded, so you can still "spawn" onto multiple threads
+                let runtime = tokio::runtime::Builder::new_multi_thread()
+                    .enable_all()
+                    .build()
+                    .unwrap();
 
-            menu_bar_menu.add_submenu("First menu", true, first_menu);
-            menu_bar_menu.add_submenu("Second menu", true, second_menu);
+                runtime.block_on(async move {
+                    let mut dom =
+                        VirtualDom::new_with_props_and_scheduler(root, props, (sender, receiver));
 
-            menu_bar_menu
-        });
+                    dom.base_scope().provide_context(desktop_context);
 
-        let window = builder.build(event_loop).unwrap();
-        let window_id = window.id();
+                    let edits = dom.rebuild();
 
-        let (is_ready, sender) = (self.is_ready.clone(), self.sender.clone());
+                    edit_queue
+                        .write()
+                        .unwrap()
+                        .push_front(serde_json::to_string(&edits.edits).unwrap());
 
-        let webview = WebViewBuilder::new(window)
+                    loop {
+                        tokio::select! {
+                            _ = dom.wait_for_work() => {}
+                            _ = &mut shutdown_rx => break,
+                        }
+                        let mut muts = dom.work_with_deadline(|| false);
+                        while let Some(edit) = muts.pop() {
+                            edit_queue
+                                .write()
+                                .unwrap()
+                                .push_front(serde_json::to_string(&edit.edits).unwrap());
+                        }
+                        let _ = evt.send_event(UserWindowEvent::Update);
+                    }
+                })
+            })
+        };
+
+        self.windows.insert(
+            window_id,
+            WindowContext {
+                sender: view_sender.clone(),
+                pending_edits: edit_queue,
+                is_ready: is_ready.clone(),
+                eval_queue,
+                eval_results: eval_results.clone(),
+                shutdown: shutdown_tx,
+                thread,
+                window_event_handler: cfg.window_event_handler.clone(),
+                quit_shortcut: cfg.quit_shortcut.clone(),
+            },
+        );
+
+        let sender = view_sender;
+        let asset_dirs = cfg.asset_dirs.clone();
+
+        let mut webview_builder = WebViewBuilder::new(window)
             .unwrap()
             .with_url("wry://index.html")
             .unwrap()
@@ -329,18 +536,44 @@ impl DesktopController {
                     "initialize" => {
                         is_ready.store(true, std::sync::atomic::Ordering::Relaxed);
                     }
+                    // The interpreter reports the result of a `DesktopContext::eval` call here,
+                    // tagged with the id we assigned it, so we can resolve the waiting future.
+                    "eval_result" => {
+                        if let Some(params) = req.params {
+                            if let Ok(EvalResult { id, result }) = serde_json::from_value(params) {
+                                if let Some(tx) = eval_results.lock().unwrap().remove(&id) {
+                                    let _ = tx.send(result);
+                                }
+                            }
+                        }
+                    }
                     _ => {}
                 }
-                // response always driven through eval.
-                // unfortunately, it seems to be pretty slow, so we might want to look into an RPC form
+                // Responses to DOM events are always driven through eval; `eval_result` above is
+                // the RPC form for snippets that need a return value back in Rust.
                 None
             })
-            // Any content that that uses the `wry://` scheme will be shuttled through this handler as a "special case"
-            // For now, we only serve two pieces of content which get included as bytes into the final binary.
+            // Any content that that uses the `wry://` scheme will be shuttled through this handler.
+            // `index.html` and `index.js` are baked into the binary; paths under `assets/` are
+            // resolved against the asset directories the app registered via
+            // `DesktopConfig::with_asset_dir`, and anything else falls back to `index.html`.
             .with_custom_protocol("wry".into(), move |request| {
                 let path = request.uri().replace("wry://", "");
-                let (data, meta) = match path.as_str() {
-                    "index.html" | "index.html/" | "/index.html" => {
+                let path = path.trim_start_matches('/');
+
+                if let Some(asset_path) = path.strip_prefix("assets/") {
+                    for dir in &asset_dirs {
+                        if let Some(candidate) = cfg::resolve_asset(dir, asset_path) {
+                            if let Ok(data) = std::fs::read(&candidate) {
+                                let meta = cfg::mime_from_path(&candidate);
+                                return wry::http::ResponseBuilder::new().mimetype(meta).body(data);
+                            }
+                        }
+                    }
+                }
+
+                let (data, meta) = match path {
+                    "index.html" | "index.html/" => {
                         (include_bytes!("./index.html").to_vec(), "text/html")
                     }
                     "index.html/index.js" => {
@@ -350,29 +583,119 @@ impl DesktopController {
                 };
 
                 wry::http::ResponseBuilder::new().mimetype(meta).body(data)
-            })
-            .build()
-            .unwrap();
+            });
+
+        for (scheme, handler) in cfg.protocols.iter().cloned() {
+            webview_builder = webview_builder
+                .with_custom_protocol(scheme, move |request| handler(request));
+        }
+
+        let webview = webview_builder.build().unwrap();
 
         self.webviews.insert(window_id, webview);
     }
 
-    pub fn close_window(&mut self, window_id: WindowId, control_flow: &mut ControlFlow) {
+    /// Remove a window's webview and stop its `VirtualDom` thread, without touching
+    /// `control_flow`.
+    fn teardown_window(&mut self, window_id: WindowId) {
         self.webviews.remove(&window_id);
+        if let Some(window) = self.windows.remove(&window_id) {
+            // The thread is parked on `dom.wait_for_work()` (or about to be); waking it with this
+            // signal is what lets it actually return instead of leaking forever once its
+            // `WindowContext` is dropped.
+            let _ = window.shutdown.send(());
+            let _ = window.thread.join();
+        }
+    }
+
+    pub fn close_window(&mut self, window_id: WindowId, control_flow: &mut ControlFlow) {
+        self.teardown_window(window_id);
 
         if self.webviews.is_empty() && self.quit_app_on_close {
             *control_flow = ControlFlow::Exit;
         }
     }
 
+    /// Tear down every open window and exit the app, regardless of how many windows are open or
+    /// whether `quit_app_on_close` is set.
+    ///
+    /// Unlike [`DesktopController::close_window`], which only exits once the *last* window closes,
+    /// this is for the quit shortcut: quitting the app is expected to quit it outright no matter
+    /// which window had focus, matching standard OS quit semantics (and the `Cmd+Q` / `Ctrl+Q`
+    /// default).
+    pub fn exit_app(&mut self, control_flow: &mut ControlFlow) {
+        let window_ids: Vec<WindowId> = self.webviews.keys().copied().collect();
+        for window_id in window_ids {
+            self.teardown_window(window_id);
+        }
+        *control_flow = ControlFlow::Exit;
+    }
+
     pub fn try_load_ready_webviews(&mut self) {
-        if self.is_ready.load(std::sync::atomic::Ordering::Relaxed) {
-            let mut queue = self.pending_edits.write().unwrap();
-            let (_id, view) = self.webviews.iter_mut().next().unwrap();
+        for (window_id, window) in self.windows.iter() {
+            if !window.is_ready.load(std::sync::atomic::Ordering::Relaxed) {
+                continue;
+            }
+
+            let view = match self.webviews.get_mut(window_id) {
+                Some(view) => view,
+                None => continue,
+            };
+
+            let mut queue = window.pending_edits.write().unwrap();
             while let Some(edit) = queue.pop_back() {
                 view.evaluate_script(&format!("window.interpreter.handleEdits({})", edit))
                     .unwrap();
             }
+            drop(queue);
+
+            let mut eval_queue = window.eval_queue.write().unwrap();
+            while let Some((id, js)) = eval_queue.pop_front() {
+                // If injecting the snippet itself fails (webview gone, etc.), the JS side never
+                // gets a chance to report back over `eval_result` — resolve the waiting future to
+                // `Value::Null` ourselves so it doesn't await forever.
+                if view.evaluate_script(&wrap_eval(id, &js)).is_err() {
+                    if let Some(tx) = window.eval_results.lock().unwrap().remove(&id) {
+                        let _ = tx.send(serde_json::Value::Null);
+                    }
+                }
+            }
         }
     }
+}
+
+/// A hook that evaluates JavaScript in the current component's webview and awaits its result.
+///
+/// Returns `None` if called outside of a desktop app (i.e. there's no [`DesktopContext`] in
+/// scope).
+///
+/// ```rust, ignore
+/// let eval = use_eval(cx).unwrap();
+/// let result = eval("return 1 + 1".to_string()).await;
+/// ```
+pub fn use_eval(cx: &ScopeState) -> Option<impl Fn(String) -> EvalFuture> {
+    let desktop = cx.consume_context::<DesktopContext>()?;
+    Some(move |js: String| desktop.eval(js))
+}
+
+/// Wrap a user-provided JS snippet so its return value is reported back over the `eval_result`
+/// RPC call, tagged with `id` so the waiting future can be matched up.
+fn wrap_eval(id: u64, js: &str) -> String {
+    format!(
+        r#"(function() {{
+            let __dioxus_eval_result = null;
+            try {{
+                __dioxus_eval_result = (function() {{ {js} }})();
+            }} catch (e) {{
+                __dioxus_eval_result = null;
+            }}
+            window.rpc.notify("eval_result", {{ id: {id}, result: __dioxus_eval_result }});
+        }})();"#
+    )
+}
+
+impl Default for DesktopController {
+    fn default() -> Self {
+        Self::new()
+    }
 }
\ No newline at end of file